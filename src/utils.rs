@@ -0,0 +1,51 @@
+/*
+ * qtcre
+ * Copyright (c) 2023 Krzysztof Saczuk <me@krzysztofsaczuk.pl>.
+ *
+ * This program is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU General Public License as published by the Free Software
+ * Foundation, either version 3 of the License, or (at your option) any later
+ * version.
+ *
+ * This program is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of  MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+/// Computes the hash Qt uses to order and look up resource names.
+///
+/// The key is hashed as UTF-16 code units, matching `qt_hash` in Qt's `qresource.cpp`.
+/// `seed` is the chained value the hash starts from; archives produced by a stock `rcc`
+/// use `0`.
+///
+/// ```
+/// assert_eq!(qtcre::qt_hash("certs", 0), 6932915);
+/// ```
+pub fn qt_hash(key: &str, seed: u32) -> u32 {
+  key.encode_utf16().fold(seed, |mut hash, unit| {
+    hash = (hash << 4).wrapping_add(unit as u32);
+    hash ^= (hash & 0xf000_0000) >> 23;
+    hash & 0x0fff_ffff
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::qt_hash;
+
+  #[test]
+  fn matches_known_hashes() {
+    assert_eq!(qt_hash("certs", 0), 6932915);
+    assert_eq!(qt_hash("small.jpg", 0), 0x082F_A507);
+  }
+
+  #[test]
+  fn hashes_utf16_code_units() {
+    assert_eq!(qt_hash("", 0), 0);
+    assert_eq!(qt_hash("", 42), 42);
+    assert_ne!(qt_hash("żółw", 0), qt_hash("zolw", 0));
+  }
+}